@@ -8,6 +8,9 @@ use std::fmt;
 pub enum AppError {
     /// Input validation failed (corresponds to HTTP 400)
     InvalidInput(String),
+
+    /// A call to an upstream embeddings service failed (corresponds to HTTP 502)
+    EmbeddingFailed(String),
 }
 
 /// Implement Display trait for user-friendly error messages.
@@ -15,6 +18,7 @@ impl fmt::Display for AppError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             AppError::InvalidInput(msg) => write!(f, "Invalid input: {msg}"),
+            AppError::EmbeddingFailed(msg) => write!(f, "Embedding request failed: {msg}"),
         }
     }
 }
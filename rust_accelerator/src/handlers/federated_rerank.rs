@@ -0,0 +1,48 @@
+//! Handler for the /federated-rerank endpoint.
+
+use crate::error::AppError;
+use crate::models::federated::FederatedReRankRequest;
+use crate::services::federated_rerank_service;
+use actix_web::{HttpResponse, Result, web};
+use log::{error, info};
+
+/// Handles POST /federated-rerank requests.
+///
+/// Receives a list of weighted query sources, re-ranks and blends them via
+/// the federated re-ranking service, and returns the merged results as JSON.
+pub async fn handle_federated_rerank(
+    req: web::Json<FederatedReRankRequest>,
+) -> Result<HttpResponse> {
+    info!(
+        "Processing federated re-rank request with {} sources",
+        req.sources.len()
+    );
+
+    match federated_rerank_service::federated_rerank(req.into_inner()).await {
+        Ok(response) => {
+            info!(
+                "Federated re-ranking completed in {}ms",
+                response.processing_time_ms
+            );
+            Ok(HttpResponse::Ok().json(response))
+        }
+        Err(e) => {
+            error!("Federated re-ranking failed: {e}",);
+
+            match e {
+                AppError::InvalidInput(msg) => {
+                    Ok(HttpResponse::BadRequest().json(serde_json::json!({
+                        "error": "Invalid input",
+                        "message": msg
+                    })))
+                }
+                AppError::EmbeddingFailed(msg) => {
+                    Ok(HttpResponse::BadGateway().json(serde_json::json!({
+                        "error": "Embedding service unavailable",
+                        "message": msg
+                    })))
+                }
+            }
+        }
+    }
+}
@@ -4,5 +4,7 @@
 //! Each handler is responsible for parsing the request, calling the appropriate
 //! service, and formatting the response.
 
+pub mod federated_rerank;
 pub mod health;
 pub mod rerank;
+pub mod similar;
@@ -24,6 +24,7 @@ pub async fn handle_rerank(req: web::Json<ReRankRequest>) -> Result<HttpResponse
         Ok(response) => {
             // Success: return results
             info!("Re-ranking completed in {}ms", response.processing_time_ms);
+            crate::get_processing_time_histogram().observe(response.processing_time_ms as f64);
             Ok(HttpResponse::Ok().json(response))
         }
         Err(e) => {
@@ -38,6 +39,12 @@ pub async fn handle_rerank(req: web::Json<ReRankRequest>) -> Result<HttpResponse
                         "message": msg
                     })))
                 }
+                AppError::EmbeddingFailed(msg) => {
+                    Ok(HttpResponse::BadGateway().json(serde_json::json!({
+                        "error": "Embedding service unavailable",
+                        "message": msg
+                    })))
+                }
             }
         }
     }
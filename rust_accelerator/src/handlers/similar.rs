@@ -0,0 +1,47 @@
+//! Handler for the /similar endpoint.
+
+use crate::error::AppError;
+use crate::models::similar::SimilarRequest;
+use crate::services::similar_service;
+use actix_web::{HttpResponse, Result, web};
+use log::{error, info};
+
+/// Handles POST /similar requests.
+///
+/// Receives a candidate set plus a target document id, and returns the other
+/// candidates most similar to that target as JSON.
+pub async fn handle_similar(req: web::Json<SimilarRequest>) -> Result<HttpResponse> {
+    info!(
+        "Processing /similar request for id '{}' against {} candidates",
+        req.id,
+        req.results.len()
+    );
+
+    match similar_service::find_similar(req.into_inner()).await {
+        Ok(response) => {
+            info!(
+                "Similarity lookup completed in {}ms",
+                response.processing_time_ms
+            );
+            Ok(HttpResponse::Ok().json(response))
+        }
+        Err(e) => {
+            error!("Similarity lookup failed: {e}",);
+
+            match e {
+                AppError::InvalidInput(msg) => {
+                    Ok(HttpResponse::BadRequest().json(serde_json::json!({
+                        "error": "Invalid input",
+                        "message": msg
+                    })))
+                }
+                AppError::EmbeddingFailed(msg) => {
+                    Ok(HttpResponse::BadGateway().json(serde_json::json!({
+                        "error": "Embedding service unavailable",
+                        "message": msg
+                    })))
+                }
+            }
+        }
+    }
+}
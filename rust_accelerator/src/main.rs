@@ -1,5 +1,5 @@
 use actix_web::{App, HttpResponse, HttpServer, Result, middleware::Logger, web};
-use prometheus::{Counter, Encoder, TextEncoder, register_counter};
+use prometheus::{Counter, Encoder, Histogram, TextEncoder, register_counter, register_histogram};
 use std::sync::OnceLock;
 
 mod error;
@@ -21,6 +21,20 @@ fn get_request_counter() -> &'static Counter {
     })
 }
 
+// Prometheus histogram of per-request processing time, so the win from
+// caching the reranker's components is measurable.
+static PROCESSING_TIME_HISTOGRAM: OnceLock<Histogram> = OnceLock::new();
+
+fn get_processing_time_histogram() -> &'static Histogram {
+    PROCESSING_TIME_HISTOGRAM.get_or_init(|| {
+        register_histogram!(
+            "rerank_processing_time_ms",
+            "Processing time of re-rank requests in milliseconds"
+        )
+        .expect("Failed to create histogram")
+    })
+}
+
 async fn metrics_handler() -> Result<HttpResponse> {
     let encoder = TextEncoder::new();
     let metric_families = prometheus::gather();
@@ -40,10 +54,16 @@ async fn main() -> std::io::Result<()> {
 
     println!(" Rust Accelerator - 2.4.0");
 
-    // Initialize metrics counter
+    // Initialize metrics counter and histogram
     let _counter = get_request_counter();
+    let _histogram = get_processing_time_histogram();
     println!(" Metrics initialized: rerank_requests_total counter ready");
 
+    // Warm up the shared reranker (and its components) up front instead of
+    // paying that cost on the first incoming request.
+    let _reranker = services::rerank_service::shared_reranker();
+    println!(" Reranker initialized: components built once, reused across requests");
+
     // Start HTTP server
     let server = HttpServer::new(|| {
         App::new()
@@ -52,6 +72,11 @@ async fn main() -> std::io::Result<()> {
             // Register routes
             .route("/health", web::get().to(handlers::health::health_check))
             .route("/re-rank", web::post().to(handlers::rerank::handle_rerank))
+            .route(
+                "/federated-rerank",
+                web::post().to(handlers::federated_rerank::handle_federated_rerank),
+            )
+            .route("/similar", web::post().to(handlers::similar::handle_similar))
             .route("/metrics", web::get().to(metrics_handler))
             // Set JSON payload size limit (default is 256KB, we increase it)
             .app_data(web::JsonConfig::default().limit(1024 * 1024)) // 1MB limit
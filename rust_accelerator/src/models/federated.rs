@@ -0,0 +1,69 @@
+//! Request/response models for the federated re-ranking API.
+//!
+//! Federated re-ranking fans a single user query out to several Qdrant
+//! collections (or otherwise independently-sourced result sets), re-ranks
+//! each source with the existing per-query pipeline, then blends the
+//! surviving results into one list using per-source weights.
+
+use super::request::{ResultMetadata, SearchResult};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// The request structure for the /federated-rerank endpoint.
+#[derive(Debug, Deserialize)]
+pub struct FederatedReRankRequest {
+    /// Independent query sources to re-rank and merge
+    pub sources: Vec<FederatedSource>,
+
+    /// Maximum number of merged results to return
+    pub limit: usize,
+}
+
+/// A single weighted query source within a federated request.
+#[derive(Debug, Deserialize)]
+pub struct FederatedSource {
+    /// The search query for this source
+    pub query: String,
+
+    /// Raw search results to re-rank for this source
+    pub results: Vec<SearchResult>,
+
+    /// Importance of this source relative to the others. Must be non-negative.
+    pub weight: f64,
+
+    /// Optional IDF boost map for this source
+    #[serde(default)]
+    pub idf_map: Option<HashMap<String, f64>>,
+
+    /// Legacy raw-score gate applied within this source's own pipeline
+    pub threshold: f64,
+}
+
+/// The response structure for the /federated-rerank endpoint.
+#[derive(Debug, Serialize)]
+pub struct FederatedReRankResponse {
+    /// Merged and re-ranked results across all sources
+    pub results: Vec<FederatedReRankedResult>,
+
+    /// Processing time in milliseconds
+    pub processing_time_ms: u64,
+}
+
+/// A single result in the merged, federated ranking.
+#[derive(Debug, Serialize)]
+pub struct FederatedReRankedResult {
+    /// Unique identifier
+    pub id: String,
+
+    /// Text content
+    pub text: String,
+
+    /// Combined score: this source's normalized ranking score times its weight
+    pub score: f64,
+
+    /// The query of the source this result came from
+    pub source_query: String,
+
+    /// Metadata
+    pub metadata: ResultMetadata,
+}
@@ -4,7 +4,10 @@
 //! but aren't exposed in our API. Think of them as implementation details.
 
 use crate::models::request::SearchResult;
+use crate::utils::fuzzy_matching::FuzzyMatcher;
+use crate::utils::query_tree::Operation;
 use std::collections::HashMap;
+use std::sync::Arc;
 
 /// Features extracted from a search query for reuse across all results.
 ///
@@ -27,6 +30,28 @@ pub struct QueryFeatures {
     pub word_frequencies: HashMap<String, usize>,
 
     pub idf_map: HashMap<String, f64>,
+
+    /// Per-term Levenshtein automaton for typo-tolerant matching, built once
+    /// per query. Keyed by single-word terms only; phrases are matched as
+    /// exact spans elsewhere and have no entry here.
+    pub fuzzy_matchers: HashMap<String, Arc<FuzzyMatcher>>,
+
+    /// Per-term scoring discount, in `[0.0, 1.0]`. Terms absent here (or
+    /// missing entirely) should be treated as full weight `1.0`; synonym-
+    /// derived terms get a lower weight so they rank below genuine query terms.
+    pub term_weights: HashMap<String, f64>,
+
+    /// Structured AND/OR/PHRASE representation of the query, mirroring the
+    /// flat maps above but preserving phrase boundaries and synonym
+    /// groupings so scoring can evaluate them recursively.
+    pub query_tree: Operation,
+
+    /// Auto-generated 2-word window phrases (e.g. "neural networks" from the
+    /// query "neural networks today"), scored as a reward on top of
+    /// `query_tree` rather than folded into it. Unlike the explicit
+    /// (quoted) phrases in `query_tree`, the caller never asked for these
+    /// specific spans, so a document shouldn't be penalized for missing one.
+    pub bonus_phrases: Vec<Operation>,
 }
 
 /// Internal wrapper for results during processing.
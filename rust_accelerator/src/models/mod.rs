@@ -3,6 +3,8 @@
 //! This module organizes all our data structures. In Rust, it's common
 //! to separate public API models from internal processing models.
 
+pub mod federated; // Models for the federated /federated-rerank endpoint
 pub mod internal;
 pub mod request; // Models for incoming requests
 pub mod response; // Models for outgoing responses // Internal models used during processing
+pub mod similar; // Models for the /similar nearest-neighbor endpoint
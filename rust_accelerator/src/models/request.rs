@@ -19,7 +19,62 @@ pub struct ReRankRequest {
     #[serde(default)]
     pub idf_map: Option<HashMap<String, f64>>,
 
+    /// Legacy raw-score gate, applied directly against `enhanced_score`.
+    ///
+    /// `enhanced_score` is an unbounded blend of signals, so this threshold
+    /// only has a consistent meaning for a given query. Prefer
+    /// `ranking_score_threshold` when comparing across queries.
     pub threshold: f64,
+
+    /// Whether to include the normalized `ranking_score` on each result.
+    #[serde(default)]
+    pub show_ranking_score: bool,
+
+    /// Optional late filter applied against the normalized `ranking_score`,
+    /// i.e. "exclude anything below 0.5 relevance" regardless of query.
+    #[serde(default)]
+    pub ranking_score_threshold: Option<f64>,
+
+    /// How much weight to give vector similarity versus the lexical pipeline.
+    /// `0.0` (the default) is pure lexical, `1.0` is pure vector similarity.
+    /// Has no effect unless `embedder` is also set.
+    #[serde(default)]
+    pub semantic_ratio: Option<f64>,
+
+    /// Embeddings endpoint to call when `semantic_ratio` is above 0.0
+    #[serde(default)]
+    pub embedder: Option<EmbedderConfig>,
+
+    /// Optional latency budget for the scoring pass. Once exceeded, any
+    /// results not yet scored fall back to their original Qdrant score
+    /// instead of the full enhanced scoring pipeline, and the response is
+    /// marked `degraded`.
+    #[serde(default)]
+    pub time_budget_ms: Option<u64>,
+
+    /// Synonyms to expand each query word with, e.g. `{"nyc": ["new york
+    /// city"]}`. Multi-word synonyms are matched as phrases rather than
+    /// loose words. Synonym-derived terms rank below genuine query terms.
+    #[serde(default)]
+    pub synonyms: Option<HashMap<String, Vec<String>>>,
+}
+
+/// Configuration for an Ollama or OpenAI-compatible `/embeddings` endpoint.
+#[derive(Debug, Clone, Deserialize)]
+pub struct EmbedderConfig {
+    /// Base URL of the embeddings service, e.g. `http://localhost:11434/api`
+    pub endpoint: String,
+
+    /// Model name to request embeddings from
+    pub model: String,
+
+    /// Request timeout in milliseconds
+    #[serde(default = "default_embedder_timeout_ms")]
+    pub timeout_ms: u64,
+}
+
+fn default_embedder_timeout_ms() -> u64 {
+    2000
 }
 
 /// A single search result from Qdrant.
@@ -11,6 +11,16 @@ pub struct ReRankResponse {
 
     /// Processing time in milliseconds
     pub processing_time_ms: u64,
+
+    /// `true` if `time_budget_ms` was exceeded and some results fell back to
+    /// their original Qdrant score instead of the full enhanced scoring pass
+    pub degraded: bool,
+
+    /// How many results went through the full enhanced scoring pipeline
+    pub scored_count: usize,
+
+    /// How many results fell back to their original score due to the time budget
+    pub passed_through_count: usize,
 }
 
 /// A single re-ranked result.
@@ -25,6 +35,12 @@ pub struct ReRankedResult {
     /// Enhanced score
     pub score: f64,
 
+    /// Enhanced score normalized into `[0.0, 1.0]` via min-max scaling over
+    /// the surviving candidate set. Only present when the request set
+    /// `show_ranking_score`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ranking_score: Option<f64>,
+
     /// Metadata
     pub metadata: ResultMetadata,
 }
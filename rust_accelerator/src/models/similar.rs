@@ -0,0 +1,43 @@
+//! Request/response models for the /similar endpoint.
+
+use super::request::{ResultMetadata, SearchResult};
+use serde::{Deserialize, Serialize};
+
+/// The request structure for the /similar endpoint.
+#[derive(Debug, Deserialize)]
+pub struct SimilarRequest {
+    /// Id of the document within `results` to find neighbors for
+    pub id: String,
+
+    /// Candidate set to search for neighbors within, including the target
+    pub results: Vec<SearchResult>,
+
+    /// Maximum number of neighbors to return
+    pub limit: usize,
+}
+
+/// The response structure for the /similar endpoint.
+#[derive(Debug, Serialize)]
+pub struct SimilarResponse {
+    /// Neighbors, most similar to the target document first
+    pub results: Vec<SimilarResult>,
+
+    /// Processing time in milliseconds
+    pub processing_time_ms: u64,
+}
+
+/// A single neighbor of the target document.
+#[derive(Debug, Serialize)]
+pub struct SimilarResult {
+    /// Unique identifier
+    pub id: String,
+
+    /// Text content
+    pub text: String,
+
+    /// Jaccard similarity to the target document's text, in `[0.0, 1.0]`
+    pub similarity: f64,
+
+    /// Metadata
+    pub metadata: ResultMetadata,
+}
@@ -0,0 +1,122 @@
+//! Embedding client for the hybrid semantic re-ranking stage.
+//!
+//! Talks to an Ollama or OpenAI-compatible `/embeddings` HTTP endpoint to turn
+//! text into vectors for cosine-similarity scoring, caching by a hash of the
+//! text for the lifetime of a single request to avoid re-embedding duplicates.
+
+use crate::error::AppError;
+use crate::models::request::EmbedderConfig;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::time::Duration;
+
+/// Cache of previously-fetched embeddings, keyed by a hash of their source text.
+pub type EmbeddingCache = HashMap<u64, Vec<f64>>;
+
+#[derive(Debug, Deserialize)]
+struct EmbeddingsResponse {
+    data: Vec<EmbeddingData>,
+}
+
+#[derive(Debug, Deserialize)]
+struct EmbeddingData {
+    embedding: Vec<f64>,
+}
+
+/// Client for an embeddings HTTP endpoint.
+pub struct Embedder {
+    client: reqwest::Client,
+    config: EmbedderConfig,
+}
+
+impl Embedder {
+    pub fn new(config: EmbedderConfig) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_millis(config.timeout_ms))
+            .build()
+            .unwrap_or_default();
+
+        Self { client, config }
+    }
+
+    /// Embeds a single piece of text (typically the query), reusing `cache`.
+    pub async fn embed_cached(
+        &self,
+        text: &str,
+        cache: &mut EmbeddingCache,
+    ) -> Result<Vec<f64>, AppError> {
+        let hash = hash_text(text);
+        if let Some(embedding) = cache.get(&hash) {
+            return Ok(embedding.clone());
+        }
+
+        let embedding = self
+            .fetch_embeddings(std::slice::from_ref(&text.to_string()))
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| AppError::EmbeddingFailed("embedder returned no vectors".to_string()))?;
+
+        cache.insert(hash, embedding.clone());
+        Ok(embedding)
+    }
+
+    /// Embeds a batch of texts, reusing `cache` across calls within the same
+    /// request so duplicate texts are only ever embedded once.
+    pub async fn embed_batch_cached(
+        &self,
+        texts: &[String],
+        cache: &mut EmbeddingCache,
+    ) -> Result<Vec<Vec<f64>>, AppError> {
+        let mut to_fetch = Vec::new();
+        let mut to_fetch_hashes = Vec::new();
+
+        for text in texts {
+            let hash = hash_text(text);
+            if !cache.contains_key(&hash) && !to_fetch_hashes.contains(&hash) {
+                to_fetch.push(text.clone());
+                to_fetch_hashes.push(hash);
+            }
+        }
+
+        if !to_fetch.is_empty() {
+            let embeddings = self.fetch_embeddings(&to_fetch).await?;
+            for (hash, embedding) in to_fetch_hashes.into_iter().zip(embeddings) {
+                cache.insert(hash, embedding);
+            }
+        }
+
+        Ok(texts
+            .iter()
+            .map(|text| cache.get(&hash_text(text)).cloned().unwrap_or_default())
+            .collect())
+    }
+
+    async fn fetch_embeddings(&self, texts: &[String]) -> Result<Vec<Vec<f64>>, AppError> {
+        let response = self
+            .client
+            .post(format!("{}/embeddings", self.config.endpoint))
+            .json(&serde_json::json!({
+                "model": self.config.model,
+                "input": texts,
+            }))
+            .send()
+            .await
+            .map_err(|e| AppError::EmbeddingFailed(format!("request failed: {e}")))?;
+
+        let parsed: EmbeddingsResponse = response
+            .json()
+            .await
+            .map_err(|e| AppError::EmbeddingFailed(format!("malformed response: {e}")))?;
+
+        Ok(parsed.data.into_iter().map(|d| d.embedding).collect())
+    }
+}
+
+fn hash_text(text: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    text.hash(&mut hasher);
+    hasher.finish()
+}
@@ -0,0 +1,114 @@
+//! Federated re-ranking across multiple weighted query sources.
+
+use crate::error::AppError;
+use crate::models::federated::{
+    FederatedReRankRequest, FederatedReRankResponse, FederatedReRankedResult, FederatedSource,
+};
+use crate::models::request::ReRankRequest;
+use crate::services::rerank_service;
+use crate::utils::similarity::SimilarityCalculator;
+use crate::utils::timing::elapsed_ms;
+use std::time::Instant;
+
+/// Main entry point for federated re-ranking.
+///
+/// Runs the existing per-query pipeline once per source, scales each
+/// source's normalized ranking score by its weight, then merges and
+/// deduplicates across sources before applying the global limit.
+pub async fn federated_rerank(
+    req: FederatedReRankRequest,
+) -> Result<FederatedReRankResponse, AppError> {
+    let start = Instant::now();
+
+    validate_input(&req)?;
+
+    // Step 1: Re-rank each source independently with the existing pipeline,
+    // then scale its normalized ranking score by the source's weight.
+    let mut candidates: Vec<FederatedReRankedResult> = Vec::new();
+    for source in req.sources {
+        let weight = source.weight;
+        let query = source.query.clone();
+        let inner_req = to_inner_request(source);
+
+        let inner_response = rerank_service::rerank_documents(inner_req).await?;
+
+        candidates.extend(inner_response.results.into_iter().map(|result| {
+            let weighted_score = result.ranking_score.unwrap_or(0.0) * weight;
+            FederatedReRankedResult {
+                id: result.id,
+                text: result.text,
+                score: weighted_score,
+                source_query: query.clone(),
+                metadata: result.metadata,
+            }
+        }));
+    }
+
+    // Step 2: Merge by weighted score, highest first.
+    candidates.sort_by(|a, b| {
+        b.score
+            .partial_cmp(&a.score)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    // Step 3: Deduplicate across sources, keeping the highest-weighted occurrence.
+    let similarity_calculator = SimilarityCalculator::new();
+    let mut merged: Vec<FederatedReRankedResult> = Vec::new();
+    for candidate in candidates {
+        let is_duplicate = merged.iter().any(|existing: &FederatedReRankedResult| {
+            similarity_calculator.jaccard_similarity(&candidate.text, &existing.text) > 0.95
+        });
+
+        if !is_duplicate {
+            merged.push(candidate);
+        }
+    }
+
+    // Step 4: Apply the global limit.
+    merged.truncate(req.limit.min(50));
+
+    let processing_time_ms = elapsed_ms(start);
+
+    Ok(FederatedReRankResponse {
+        results: merged,
+        processing_time_ms,
+    })
+}
+
+/// Builds the per-source request passed into the existing single-query pipeline.
+///
+/// `show_ranking_score` is forced on since the normalized score is what gets
+/// scaled by the source's weight, and `limit` is left uncapped (beyond the
+/// pipeline's own 50-result cap) so weighting sees the full surviving set.
+fn to_inner_request(source: FederatedSource) -> ReRankRequest {
+    ReRankRequest {
+        query: source.query,
+        limit: source.results.len(),
+        results: source.results,
+        idf_map: source.idf_map,
+        threshold: source.threshold,
+        show_ranking_score: true,
+        ranking_score_threshold: None,
+        semantic_ratio: None,
+        embedder: None,
+        time_budget_ms: None,
+        synonyms: None,
+    }
+}
+
+/// Validates the incoming federated request.
+fn validate_input(req: &FederatedReRankRequest) -> Result<(), AppError> {
+    if req.sources.is_empty() {
+        return Err(AppError::InvalidInput(
+            "Sources list is empty".to_string(),
+        ));
+    }
+
+    if req.sources.iter().any(|source| source.weight < 0.0) {
+        return Err(AppError::InvalidInput(
+            "Source weights must be non-negative".to_string(),
+        ));
+    }
+
+    Ok(())
+}
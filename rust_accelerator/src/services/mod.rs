@@ -0,0 +1,6 @@
+//! Business logic services for the re-ranking API.
+
+pub mod embedding_service;
+pub mod federated_rerank_service;
+pub mod rerank_service;
+pub mod similar_service;
@@ -2,11 +2,14 @@ use crate::error::AppError;
 use crate::models::internal::EnhancedResult;
 use crate::models::request::ReRankRequest;
 use crate::models::response::{ReRankResponse, ReRankedResult};
+use crate::services::embedding_service::{Embedder, EmbeddingCache};
 use crate::utils::filtering::ResultFilter;
 use crate::utils::scoring::ScoreCalculator;
-use crate::utils::similarity::SimilarityCalculator;
+use crate::utils::similarity::{SimilarityCalculator, cosine_similarity};
 use crate::utils::text_analysis::TextAnalyzer;
 use crate::utils::timing::elapsed_ms;
+use log::warn;
+use std::sync::OnceLock;
 use std::time::Instant;
 
 /// Re-ranking service combining multiple algorithms for optimal results.
@@ -26,11 +29,16 @@ pub struct DocumentReRanker {
 
 impl DocumentReRanker {
     /// Creates a new instance with all components.
-    pub fn new(req: &ReRankRequest) -> Self {
+    ///
+    /// Every component here is request-independent (the per-request
+    /// `threshold` is passed through to `rerank_documents` instead), so a
+    /// single instance is meant to be built once and reused; see
+    /// [`shared_reranker`].
+    pub fn new() -> Self {
         Self {
             text_analyzer: TextAnalyzer::new(),
             score_calculator: ScoreCalculator::new(),
-            result_filter: ResultFilter::new(req.threshold),
+            result_filter: ResultFilter::new(),
             similarity_calculator: SimilarityCalculator::new(),
         }
     }
@@ -45,37 +53,58 @@ impl DocumentReRanker {
         // Step 2: Query preprocessing
         let query_features = self
             .text_analyzer
-            .extract_query_features(&req.query, req.idf_map.clone());
+            .extract_query_features(&req.query, req.idf_map.clone(), req.synonyms.clone());
 
         // Step 3: Parallel result processing with domain filtering
-        let mut enhanced_results: Vec<EnhancedResult> = req
-            .results
-            .into_iter()
-            .enumerate()
-            .filter_map(|(position, result)| {
-                // Use domain-aware filtering instead of simple filtering
-                if !self
-                    .result_filter
-                    .should_keep_with_query(&result, &req.query)
-                {
-                    return None; // `None` means "skip this item"
-                }
+        // `avgdl` is a per-batch constant used to length-normalize the BM25
+        // keyword boost, so it's computed once up front rather than per result.
+        let avgdl = average_word_count(&req.results);
 
-                // Calculate enhanced score using multiple algorithms
-                let enhanced_score = self.score_calculator.calculate_enhanced_score(
+        // Once `time_budget_ms` is exceeded, remaining results fall back to
+        // their original Qdrant score instead of going through full scoring,
+        // so the pipeline stays within a predictable latency envelope.
+        let mut degraded = false;
+        let mut scored_count = 0usize;
+        let mut passed_through_count = 0usize;
+
+        let mut enhanced_results: Vec<EnhancedResult> = Vec::with_capacity(req.results.len());
+        for (position, result) in req.results.into_iter().enumerate() {
+            // Use domain-aware filtering instead of simple filtering
+            if !self
+                .result_filter
+                .should_keep_with_query(&result, &req.query, req.threshold)
+            {
+                continue;
+            }
+
+            if !degraded
+                && req
+                    .time_budget_ms
+                    .map_or(false, |budget| elapsed_ms(start) >= budget)
+            {
+                degraded = true;
+            }
+
+            let enhanced_score = if degraded {
+                passed_through_count += 1;
+                result.score // Fall back to the original Qdrant score
+            } else {
+                scored_count += 1;
+                self.score_calculator.calculate_enhanced_score(
                     &result,
                     &query_features,
                     position,
-                );
-
-                // Wrap in our internal struct for further processing
-                Some(EnhancedResult {
-                    original: result,
-                    enhanced_score,
-                    original_position: position,
-                })
-            })
-            .collect(); // Convert iterator back to Vec
+                    avgdl,
+                )
+            };
+
+            // Wrap in our internal struct for further processing
+            enhanced_results.push(EnhancedResult {
+                original: result,
+                enhanced_score,
+                original_position: position,
+            });
+        }
 
         //* */ === STEP 4: ADVANCED SORTING ===
         // Sort by enhanced score (descending), with original position as tiebreaker
@@ -95,16 +124,55 @@ impl DocumentReRanker {
             .similarity_calculator
             .remove_duplicates(enhanced_results);
 
-        //* */ === STEP 6: FINAL FORMATTING ===
-        // Convert to response format and apply limit
-        let final_results: Vec<ReRankedResult> = deduplicated
+        //* */ === STEP 5.5: HYBRID SEMANTIC RE-SCORING (optional) ===
+        // Blend in vector similarity when the caller opted in via `semantic_ratio`
+        let (mut deduplicated, hybrid_applied) =
+            self.apply_hybrid_scoring(deduplicated, &req).await;
+
+        // The blend in `apply_hybrid_scoring` changes the relative order of
+        // results (a document can rank high on lexical score but low on
+        // cosine similarity, or vice versa), so the set has to be re-sorted
+        // by the blended score before anything downstream picks a top-N.
+        if hybrid_applied {
+            deduplicated.sort_by(|a, b| {
+                match b.enhanced_score.partial_cmp(&a.enhanced_score) {
+                    Some(std::cmp::Ordering::Equal) => {
+                        a.original_position.cmp(&b.original_position)
+                    }
+                    other => other.unwrap_or(std::cmp::Ordering::Equal),
+                }
+            });
+        }
+
+        //* */ === STEP 6: NORMALIZATION + FINAL FORMATTING ===
+        // Apply the legacy raw-score gate first (kept for backward compatibility),
+        // then normalize the surviving set so `ranking_score_threshold` means the
+        // same thing regardless of how the raw scores for this query are spread.
+        //
+        // `req.threshold` is calibrated against the pipeline's native lexical
+        // scale; once hybrid rescoring has blended in cosine similarity, the
+        // score lives on a rescaled `[0.0, 1.0]` axis and the legacy gate no
+        // longer means the same thing, so it's skipped in that case.
+        let legacy_filtered: Vec<EnhancedResult> = deduplicated
+            .into_iter()
+            .filter(|enhanced| hybrid_applied || enhanced.enhanced_score >= req.threshold)
+            .collect();
+
+        let ranking_scores = Self::normalize_scores(&legacy_filtered);
+
+        let final_results: Vec<ReRankedResult> = legacy_filtered
             .into_iter()
-            .filter(|enhanced| enhanced.enhanced_score >= req.threshold) // Final threshold check
+            .zip(ranking_scores)
+            .filter(|(_, ranking_score)| {
+                req.ranking_score_threshold
+                    .map_or(true, |t| *ranking_score >= t)
+            })
             .take(req.limit.min(50)) // Cap at 50 for performance
-            .map(|enhanced| ReRankedResult {
+            .map(|(enhanced, ranking_score)| ReRankedResult {
                 id: enhanced.original.id,
                 text: enhanced.original.text,
                 score: enhanced.enhanced_score,
+                ranking_score: req.show_ranking_score.then_some(ranking_score),
                 metadata: enhanced.original.metadata,
             })
             .collect();
@@ -114,6 +182,9 @@ impl DocumentReRanker {
         Ok(ReRankResponse {
             results: final_results,
             processing_time_ms,
+            degraded,
+            scored_count,
+            passed_through_count,
         })
     }
 
@@ -140,15 +211,178 @@ impl DocumentReRanker {
 
         Ok(()) // Success case - no error
     }
+
+    /// Blends in vector similarity against the query, turning the lexical-only
+    /// pipeline into a hybrid re-ranker.
+    ///
+    /// `final = (1 - semantic_ratio) * lexical_norm + semantic_ratio * cosine`.
+    /// Embeddings are cached by a hash of their text for the lifetime of this
+    /// call so duplicate texts are only embedded once. Falls back to the
+    /// untouched lexical scores (logging a warning) if the embedder call
+    /// fails or times out, or if `semantic_ratio`/`embedder` weren't set.
+    ///
+    /// Returns whether rescoring actually happened alongside the results, so
+    /// the caller can re-sort by the new score and skip the legacy raw-score
+    /// gate, which is calibrated for the lexical scale rather than this
+    /// blended `[0.0, 1.0]` one.
+    async fn apply_hybrid_scoring(
+        &self,
+        results: Vec<EnhancedResult>,
+        req: &ReRankRequest,
+    ) -> (Vec<EnhancedResult>, bool) {
+        let ratio = req.semantic_ratio.unwrap_or(0.0);
+        if ratio <= 0.0 || results.is_empty() {
+            return (results, false);
+        }
+
+        let Some(embedder_config) = req.embedder.clone() else {
+            return (results, false);
+        };
+
+        let embedder = Embedder::new(embedder_config);
+        let mut cache: EmbeddingCache = EmbeddingCache::new();
+
+        let query_embedding = match embedder.embed_cached(&req.query, &mut cache).await {
+            Ok(embedding) => embedding,
+            Err(e) => {
+                warn!("Hybrid re-ranking: falling back to lexical scoring, query embed failed: {e}");
+                return (results, false);
+            }
+        };
+
+        let texts: Vec<String> = results.iter().map(|r| r.original.text.clone()).collect();
+        let embeddings = match embedder.embed_batch_cached(&texts, &mut cache).await {
+            Ok(embeddings) => embeddings,
+            Err(e) => {
+                warn!("Hybrid re-ranking: falling back to lexical scoring, result embed failed: {e}");
+                return (results, false);
+            }
+        };
+
+        let lexical_norm = Self::normalize_scores(&results);
+
+        let rescored = results
+            .into_iter()
+            .zip(lexical_norm)
+            .zip(embeddings)
+            .map(|((mut enhanced, lexical), embedding)| {
+                let cosine = cosine_similarity(&query_embedding, &embedding);
+                enhanced.enhanced_score = (1.0 - ratio) * lexical + ratio * cosine;
+                enhanced
+            })
+            .collect();
+
+        (rescored, true)
+    }
+
+    /// Normalizes enhanced scores into `[0.0, 1.0]` via min-max scaling over
+    /// the surviving candidate set.
+    ///
+    /// Guards against the degenerate case where every score is equal
+    /// (`max == min`), which would otherwise divide by zero, by mapping
+    /// everything to 1.0.
+    fn normalize_scores(results: &[EnhancedResult]) -> Vec<f64> {
+        let min = results
+            .iter()
+            .map(|r| r.enhanced_score)
+            .fold(f64::INFINITY, f64::min);
+        let max = results
+            .iter()
+            .map(|r| r.enhanced_score)
+            .fold(f64::NEG_INFINITY, f64::max);
+
+        if (max - min).abs() < f64::EPSILON {
+            return vec![1.0; results.len()];
+        }
+
+        results
+            .iter()
+            .map(|r| (r.enhanced_score - min) / (max - min))
+            .collect()
+    }
 }
 
-/// Public interface function that HTTP handler will call.
+/// Computes the mean word count across a batch of search results, for BM25
+/// length normalization. Defaults to 1.0 for an empty batch to avoid a
+/// division by zero downstream.
+fn average_word_count(results: &[crate::models::request::SearchResult]) -> f64 {
+    if results.is_empty() {
+        return 1.0;
+    }
+
+    let total_words: usize = results
+        .iter()
+        .map(|r| r.text.split_whitespace().count())
+        .sum();
+
+    (total_words as f64 / results.len() as f64).max(1.0)
+}
+
+/// Process-wide `DocumentReRanker`, built once on first use.
 ///
-/// Creates a new DocumentReRanker instance for each request to ensure
-/// the correct threshold is used. The performance impact is minimal since
-/// the expensive operations (text analysis, scoring) happen during processing,
-/// not during initialization.
+/// `TextAnalyzer`, `ScoreCalculator`, `ResultFilter` (including its
+/// `DomainClassifier`), and `SimilarityCalculator` are all expensive to
+/// build and entirely request-independent, so rebuilding them per request
+/// was pure waste on the hot path. Only the per-request `threshold` still
+/// varies, and that's threaded through `rerank_documents` as an argument.
+static SHARED_RERANKER: OnceLock<DocumentReRanker> = OnceLock::new();
+
+/// Returns the shared `DocumentReRanker`, initializing it on first access.
+pub fn shared_reranker() -> &'static DocumentReRanker {
+    SHARED_RERANKER.get_or_init(DocumentReRanker::new)
+}
+
+/// Public interface function that HTTP handlers and other services call.
+///
+/// Reuses the process-wide `DocumentReRanker` rather than constructing a
+/// fresh one per request.
 pub async fn rerank_documents(req: ReRankRequest) -> Result<ReRankResponse, AppError> {
-    let reranker = DocumentReRanker::new(&req);
-    reranker.rerank_documents(req).await
+    shared_reranker().rerank_documents(req).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::request::{ResultMetadata, SearchResult};
+
+    fn enhanced(score: f64) -> EnhancedResult {
+        EnhancedResult {
+            original: SearchResult {
+                id: "doc".into(),
+                text: String::new(),
+                score,
+                metadata: ResultMetadata {
+                    file_name: "doc.txt".into(),
+                    content_type: "text/plain".into(),
+                    page_number: None,
+                    section_title: None,
+                },
+            },
+            enhanced_score: score,
+            original_position: 0,
+        }
+    }
+
+    #[test]
+    fn normalize_scores_min_max_scales_into_unit_range() {
+        let results = vec![enhanced(1.0), enhanced(3.0), enhanced(5.0)];
+
+        let normalized = DocumentReRanker::normalize_scores(&results);
+
+        assert_eq!(normalized, vec![0.0, 0.5, 1.0]);
+    }
+
+    #[test]
+    fn normalize_scores_guards_against_equal_scores() {
+        let results = vec![enhanced(2.0), enhanced(2.0), enhanced(2.0)];
+
+        let normalized = DocumentReRanker::normalize_scores(&results);
+
+        assert_eq!(normalized, vec![1.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn normalize_scores_of_empty_set_is_empty() {
+        assert!(DocumentReRanker::normalize_scores(&[]).is_empty());
+    }
 }
@@ -0,0 +1,89 @@
+//! Intra-result-set nearest neighbor lookup ("more like this one").
+
+use crate::error::AppError;
+use crate::models::similar::{SimilarRequest, SimilarResponse, SimilarResult};
+use crate::utils::similarity::SimilarityCalculator;
+use crate::utils::timing::elapsed_ms;
+use std::time::Instant;
+
+/// Deduplication threshold shared with the main re-ranking pipeline.
+const DEDUPLICATION_THRESHOLD: f64 = 0.95;
+
+/// Main entry point for the /similar endpoint.
+///
+/// Scores every other candidate in `results` against the target document's
+/// text, sorts by descending similarity, and applies the same dedup and
+/// `limit` logic as the main re-ranking path.
+pub async fn find_similar(req: SimilarRequest) -> Result<SimilarResponse, AppError> {
+    let start = Instant::now();
+
+    validate_input(&req)?;
+
+    let target = req
+        .results
+        .iter()
+        .find(|result| result.id == req.id)
+        .cloned()
+        .ok_or_else(|| {
+            AppError::InvalidInput(format!("id '{}' not found in results", req.id))
+        })?;
+
+    let similarity_calculator = SimilarityCalculator::new();
+
+    let mut scored: Vec<SimilarResult> = req
+        .results
+        .into_iter()
+        .filter(|result| result.id != req.id)
+        .map(|result| {
+            let similarity = similarity_calculator.jaccard_similarity(&target.text, &result.text);
+            SimilarResult {
+                id: result.id,
+                text: result.text,
+                similarity,
+                metadata: result.metadata,
+            }
+        })
+        .collect();
+
+    scored.sort_by(|a, b| {
+        b.similarity
+            .partial_cmp(&a.similarity)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    // Deduplicate near-identical neighbors using the same Jaccard threshold
+    // the main pipeline's SimilarityCalculator uses.
+    let mut deduplicated: Vec<SimilarResult> = Vec::new();
+    for candidate in scored {
+        let is_duplicate = deduplicated.iter().any(|existing: &SimilarResult| {
+            similarity_calculator.jaccard_similarity(&candidate.text, &existing.text)
+                > DEDUPLICATION_THRESHOLD
+        });
+
+        if !is_duplicate {
+            deduplicated.push(candidate);
+        }
+    }
+
+    deduplicated.truncate(req.limit.min(50));
+
+    Ok(SimilarResponse {
+        results: deduplicated,
+        processing_time_ms: elapsed_ms(start),
+    })
+}
+
+/// Validates the incoming request for basic sanity.
+fn validate_input(req: &SimilarRequest) -> Result<(), AppError> {
+    if req.results.is_empty() {
+        return Err(AppError::InvalidInput("Results list is empty".into()));
+    }
+
+    if req.limit == 0 {
+        return Err(AppError::InvalidInput(
+            "Limit must be greater than 0".into(),
+        ));
+    }
+
+    Ok(())
+}
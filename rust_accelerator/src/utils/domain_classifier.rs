@@ -0,0 +1,189 @@
+//! Data-driven domain/interest classification for relevance filtering.
+//!
+//! Replaces hardcoded keyword lists with named interests loaded from a JSON
+//! config file at startup, each carrying a weighted keyword vector and a
+//! classification cutoff, so operators can add domains or retune thresholds
+//! without recompiling.
+
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+
+/// Path to the domain classification config, relative to the working directory.
+const DOMAIN_CONFIG_PATH: &str = "config/domains.json";
+
+/// A single named interest with a weighted keyword vector.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DomainInterest {
+    /// Label applied to text that scores above `cutoff` for this domain
+    pub name: String,
+
+    /// Keyword -> weight. Higher weights contribute more to the domain score.
+    pub keywords: HashMap<String, f64>,
+
+    /// Per-token domain score that must be exceeded to apply `name` as a label
+    #[serde(default = "default_cutoff")]
+    pub cutoff: f64,
+}
+
+fn default_cutoff() -> f64 {
+    0.03
+}
+
+#[derive(Debug, Deserialize)]
+struct DomainConfigFile {
+    domains: Vec<DomainInterest>,
+}
+
+/// Classifies text into zero or more named domains based on a weighted
+/// keyword vector per domain, loaded from `DOMAIN_CONFIG_PATH` at startup.
+pub struct DomainClassifier {
+    domains: Vec<DomainInterest>,
+}
+
+impl DomainClassifier {
+    /// Loads the classifier from `DOMAIN_CONFIG_PATH`, falling back to a
+    /// small built-in default set if the file is missing or malformed so a
+    /// fresh checkout still filters sensibly.
+    pub fn load() -> Self {
+        let domains = fs::read_to_string(DOMAIN_CONFIG_PATH)
+            .map_err(|e| e.to_string())
+            .and_then(|contents| {
+                serde_json::from_str::<DomainConfigFile>(&contents).map_err(|e| e.to_string())
+            })
+            .map(|config| config.domains)
+            .unwrap_or_else(|e| {
+                log::warn!(
+                    "Falling back to built-in domain config, couldn't load {DOMAIN_CONFIG_PATH}: {e}"
+                );
+                Self::default_domains()
+            });
+
+        Self { domains }
+    }
+
+    /// Computes a per-domain relevance vector for `text`: for each domain,
+    /// the sum of matched keyword weights divided by the text's token count.
+    pub fn score_vector(&self, text: &str) -> HashMap<String, f64> {
+        let token_count = text.split_whitespace().count().max(1) as f64;
+
+        self.domains
+            .iter()
+            .map(|domain| {
+                let score: f64 = domain
+                    .keywords
+                    .iter()
+                    .filter(|(keyword, _)| text.contains(keyword.as_str()))
+                    .map(|(_, weight)| weight)
+                    .sum();
+
+                (domain.name.clone(), score / token_count)
+            })
+            .collect()
+    }
+
+    /// Labels `text` with every domain whose score exceeds its cutoff,
+    /// falling back to `"general"` when none do.
+    pub fn classify(&self, text: &str) -> Vec<String> {
+        let vector = self.score_vector(text);
+
+        let mut labels: Vec<String> = self
+            .domains
+            .iter()
+            .filter(|domain| vector.get(&domain.name).copied().unwrap_or(0.0) > domain.cutoff)
+            .map(|domain| domain.name.clone())
+            .collect();
+
+        if labels.is_empty() {
+            labels.push("general".to_string());
+        }
+
+        labels
+    }
+
+    /// Cosine similarity between two domain-score vectors, aligned by domain name.
+    ///
+    /// Returns `1.0` when either vector has no domain signal at all (mirrors
+    /// the prior "no domain classification, assume compatible" fallback).
+    pub fn cosine_relevance(&self, a: &HashMap<String, f64>, b: &HashMap<String, f64>) -> f64 {
+        let get = |vector: &HashMap<String, f64>, name: &str| vector.get(name).copied().unwrap_or(0.0);
+
+        let dot: f64 = self
+            .domains
+            .iter()
+            .map(|d| get(a, &d.name) * get(b, &d.name))
+            .sum();
+        let norm_a: f64 = self
+            .domains
+            .iter()
+            .map(|d| get(a, &d.name).powi(2))
+            .sum::<f64>()
+            .sqrt();
+        let norm_b: f64 = self
+            .domains
+            .iter()
+            .map(|d| get(b, &d.name).powi(2))
+            .sum::<f64>()
+            .sqrt();
+
+        if norm_a == 0.0 || norm_b == 0.0 {
+            return 1.0;
+        }
+
+        dot / (norm_a * norm_b)
+    }
+
+    /// Built-in defaults, used when the config file can't be loaded.
+    ///
+    /// Mirrors the domain keyword lists that used to be hardcoded in
+    /// `ResultFilter` (see `filtering.rs`), each keyword at weight 1.0.
+    fn default_domains() -> Vec<DomainInterest> {
+        let weighted = |words: &[&str]| -> HashMap<String, f64> {
+            words.iter().map(|&w| (w.to_string(), 1.0)).collect()
+        };
+
+        vec![
+            DomainInterest {
+                name: "technology".to_string(),
+                keywords: weighted(&[
+                    "javascript", "python", "programming", "code", "function", "variable",
+                    "computer", "technology", "api", "database", "web", "app", "development",
+                    "framework", "library", "script", "debug", "compile", "syntax", "class",
+                    "method", "object", "array", "loop", "frontend", "backend", "html", "css",
+                    "node", "react", "angular", "vue", "typescript", "coding", "developer",
+                ]),
+                cutoff: 0.03,
+            },
+            DomainInterest {
+                name: "biology".to_string(),
+                keywords: weighted(&[
+                    "panda", "animal", "species", "habitat", "conservation", "wildlife",
+                    "forest", "bamboo", "ecology", "biodiversity", "endangered", "mammal",
+                    "genetics", "population", "ecosystem", "natural", "environment",
+                    "biological", "organism", "zoological", "fauna", "flora", "mitochondrial",
+                    "dna", "genetic", "phylogenetic", "taxonomic", "morphological",
+                ]),
+                cutoff: 0.03,
+            },
+            DomainInterest {
+                name: "science".to_string(),
+                keywords: weighted(&[
+                    "hypothesis", "methodology", "statistical", "survey", "sample",
+                    "scientific", "publication", "peer", "review", "findings", "conclusion",
+                    "evidence", "theory", "model", "observation", "measurement", "laboratory",
+                    "experiment", "clinical", "empirical",
+                ]),
+                cutoff: 0.03,
+            },
+            DomainInterest {
+                name: "business".to_string(),
+                keywords: weighted(&[
+                    "business", "finance", "market", "investment", "revenue", "profit",
+                    "company", "corporate", "management", "strategy", "economics",
+                    "financial", "banking", "trade", "commerce", "industry", "sales",
+                ]),
+                cutoff: 0.03,
+            },
+        ]
+    }
+}
@@ -0,0 +1,123 @@
+//! Typo-tolerant keyword matching via Levenshtein automata.
+//!
+//! Builds one Levenshtein DFA per query term (modeled on the distance-bucketed
+//! approach used by typo-tolerant search engines) so that tokens in result
+//! text can be matched within an edit distance without re-running dynamic
+//! programming per comparison.
+
+use levenshtein_automata::{Distance, LevenshteinAutomatonBuilder, DFA};
+use std::collections::HashMap;
+
+/// Picks a max edit distance for a query term based on its length: exact
+/// match only for very short terms, widening as terms get longer.
+pub fn max_edit_distance_for(term: &str) -> u8 {
+    match term.chars().count() {
+        0..=4 => 0,
+        5..=8 => 1,
+        _ => 2,
+    }
+}
+
+/// A compiled fuzzy matcher for a single query term.
+pub struct FuzzyMatcher {
+    dfa: DFA,
+    /// Max edit distance this matcher was built with, bucketed by term length.
+    pub max_distance: u8,
+    term_len: usize,
+}
+
+impl std::fmt::Debug for FuzzyMatcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FuzzyMatcher")
+            .field("max_distance", &self.max_distance)
+            .field("term_len", &self.term_len)
+            .finish()
+    }
+}
+
+impl FuzzyMatcher {
+    /// Builds a Levenshtein automaton for `term` at its length-bucketed max
+    /// edit distance.
+    pub fn build(term: &str) -> Self {
+        let max_distance = max_edit_distance_for(term);
+        let builder = LevenshteinAutomatonBuilder::new(max_distance, true);
+
+        Self {
+            dfa: builder.build_dfa(term),
+            max_distance,
+            term_len: term.chars().count(),
+        }
+    }
+
+    /// Tests `word` against this matcher, returning the edit distance on a match.
+    ///
+    /// Guards against the "split word" failure mode where a short fragment
+    /// (e.g. "s") matches a longer term at distance 0 purely because the
+    /// automaton tolerates insertions: single-character words never
+    /// fuzzy-match, and words far outside the automaton's construction
+    /// length are rejected before consulting the DFA.
+    pub fn matches(&self, word: &str) -> Option<u8> {
+        if word.chars().count() <= 1 {
+            return None;
+        }
+
+        let length_diff = (word.chars().count() as i64 - self.term_len as i64).unsigned_abs();
+        if length_diff > self.max_distance as u64 {
+            return None;
+        }
+
+        match self.dfa.eval(word) {
+            Distance::Exact(d) if d <= self.max_distance => Some(d),
+            _ => None,
+        }
+    }
+}
+
+/// Builds a fuzzy matcher per single-word query term. Phrases (terms
+/// containing a space) are skipped since they're matched as exact spans
+/// elsewhere.
+pub fn build_matchers(terms: impl IntoIterator<Item = String>) -> HashMap<String, FuzzyMatcher> {
+    terms
+        .into_iter()
+        .filter(|term| !term.contains(' '))
+        .map(|term| {
+            let matcher = FuzzyMatcher::build(&term);
+            (term, matcher)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_exact_term() {
+        let matcher = FuzzyMatcher::build("machine");
+        assert_eq!(matcher.matches("machine"), Some(0));
+    }
+
+    #[test]
+    fn matches_one_typo_within_bucketed_distance() {
+        let matcher = FuzzyMatcher::build("machine");
+        assert_eq!(matcher.matches("machien"), Some(1));
+    }
+
+    #[test]
+    fn rejects_single_character_words() {
+        let matcher = FuzzyMatcher::build("machine");
+        assert_eq!(matcher.matches("s"), None);
+    }
+
+    #[test]
+    fn rejects_words_far_outside_construction_length() {
+        let matcher = FuzzyMatcher::build("ai");
+        assert_eq!(matcher.matches("artificial"), None);
+    }
+
+    #[test]
+    fn rejects_unrelated_word_of_similar_length() {
+        let matcher = FuzzyMatcher::build("machine");
+        assert_eq!(matcher.matches("teacher"), None);
+    }
+}
@@ -1,6 +1,9 @@
 //! Utility modules for the re-ranking service.
 
+pub mod domain_classifier;
 pub mod filtering;
+pub mod fuzzy_matching;
+pub mod query_tree;
 pub mod scoring;
 pub mod similarity;
 pub mod text_analysis;
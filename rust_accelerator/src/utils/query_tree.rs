@@ -0,0 +1,39 @@
+//! Structured representation of a query as an AND/OR/PHRASE tree.
+//!
+//! A flat term bag can't distinguish "must match this exact phrase" from
+//! "match this word or one of its synonyms", so scoring has to treat every
+//! term the same way. This tree preserves that structure instead: built once
+//! by `TextAnalyzer` alongside the existing flat maps, then walked
+//! recursively by `ScoreCalculator::calculate_keyword_boost`.
+
+/// A node in a structured query.
+#[derive(Debug, Clone)]
+pub enum Operation {
+    /// All children are expected to match; their scores are summed, with a
+    /// penalty applied for children that don't match at all.
+    And(Vec<Operation>),
+    /// Any child may match; the node scores as its best-matching child.
+    Or(Vec<Operation>),
+    /// An ordered, contiguous span of words, e.g. `["neural", "networks"]`,
+    /// scored only on an exact match of the whole span.
+    Phrase(Vec<String>),
+    /// A single (already-stemmed) term.
+    Term(String),
+}
+
+impl Operation {
+    /// Collects every single-word `Term` leaf's string into `out`. `Phrase`
+    /// leaves are skipped — they already require contiguity by definition,
+    /// so proximity scoring over loose terms doesn't add anything for them.
+    pub fn collect_terms(&self, out: &mut Vec<String>) {
+        match self {
+            Operation::Term(term) => out.push(term.clone()),
+            Operation::Phrase(_) => {}
+            Operation::And(children) | Operation::Or(children) => {
+                for child in children {
+                    child.collect_terms(out);
+                }
+            }
+        }
+    }
+}
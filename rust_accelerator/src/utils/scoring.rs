@@ -2,6 +2,8 @@
 
 use crate::models::internal::QueryFeatures;
 use crate::models::request::SearchResult;
+use crate::utils::query_tree::Operation;
+use std::collections::HashMap;
 
 /// Configuration for score weighting.
 pub struct ScoreWeights {
@@ -10,6 +12,17 @@ pub struct ScoreWeights {
     pub length_optimization: f64, // Ideal length weight
     pub position_decay: f64,      // Position weight
     pub completeness: f64,        // Completeness weight
+
+    /// BM25 term-frequency saturation parameter. Higher values let repeated
+    /// terms keep contributing longer before tf saturates.
+    pub bm25_k1: f64,
+    /// BM25 length normalization parameter, in `[0.0, 1.0]`. `0.0` disables
+    /// length normalization, `1.0` applies it fully.
+    pub bm25_b: f64,
+
+    /// Weight for the proximity boost, which rewards matched query terms
+    /// appearing close together in the result text.
+    pub proximity: f64,
 }
 
 impl Default for ScoreWeights {
@@ -20,6 +33,9 @@ impl Default for ScoreWeights {
             length_optimization: 0.2,
             position_decay: 0.02,
             completeness: 0.05,
+            bm25_k1: 1.2,
+            bm25_b: 0.75,
+            proximity: 0.15,
         }
     }
 }
@@ -37,11 +53,16 @@ impl ScoreCalculator {
     }
 
     /// Calculates an enhanced score using multiple strategies.
+    ///
+    /// `avgdl` is the mean word count across the whole `results` batch,
+    /// computed once per request and used to length-normalize the BM25
+    /// keyword boost below.
     pub fn calculate_enhanced_score(
         &self,
         result: &SearchResult,
         query_features: &QueryFeatures,
         position: usize,
+        avgdl: f64,
     ) -> f64 {
         // Start with the original Qdrant similarity score
         let base_score = result.score;
@@ -51,9 +72,10 @@ impl ScoreCalculator {
         let quality_factor = self.calculate_text_quality_factor(&result.text);
         let quality_adjustment = (quality_factor - 1.0) * self.weights.text_quality;
 
-        //* */ === ALGORITHM 2: KEYWORD MATCHING BOOST ===
+        //* */ === ALGORITHM 2: KEYWORD MATCHING BOOST (BM25) ===
         //* */ This should be additive to reward exact matches
-        let keyword_boost = self.calculate_keyword_boost(&result.text, query_features);
+        let dl = result.text.split_whitespace().count() as f64;
+        let keyword_boost = self.calculate_keyword_boost(&result.text, query_features, dl, avgdl);
 
         //* */ === ALGORITHM 3: LENGTH OPTIMIZATION ===
         //* */ Apply as smaller adjustment, not harsh multiplier
@@ -69,6 +91,11 @@ impl ScoreCalculator {
         //* */ Small bonus for complete sentences and well-formed text
         let completeness_bonus = self.calculate_completeness_bonus(&result.text);
 
+        //* */ === ALGORITHM 6: PROXIMITY SCORING ===
+        //* */ Additive, like the keyword boost, rewards matched terms sitting
+        //* */ close together instead of scattered across the passage
+        let proximity_boost = self.calculate_proximity_boost(&result.text, query_features);
+
         //* */ Combine all factors more conservatively
         let mut final_score = base_score;
         final_score += quality_adjustment; // Add/subtract quality
@@ -76,6 +103,7 @@ impl ScoreCalculator {
         final_score += length_adjustment; // Add/subtract length
         final_score *= 1.0 - position_penalty; // Small position penalty
         final_score += completeness_bonus * self.weights.completeness; // Add completeness
+        final_score += proximity_boost * self.weights.proximity; // Add proximity boost
 
         //* */ Ensure score stays in valid range but allow enhancement above 1.0
         final_score.clamp(0.0, 2.0)
@@ -117,46 +145,252 @@ impl ScoreCalculator {
         }
     }
 
-    /// Calculates keyword matching boost using phrase-aware scoring.
+    /// Calculates keyword matching boost by recursively evaluating the
+    /// query's `Operation` tree (see `QueryFeatures::query_tree`) against the
+    /// result text.
+    ///
+    /// Each leaf scores independently:
+    /// - `Term` uses BM25 term weighting with typo-tolerant fuzzy matching:
+    ///
+    ///   ```text
+    ///   idf(t) * (tf * (k1 + 1)) / (tf + k1 * (1 - b + b * (dl / avgdl)))
+    ///   ```
     ///
-    /// This algorithm rewards results that contain words and phrases from the search query.
-    /// It provides stronger boosts for:
-    /// 1. Exact phrase matches (e.g. "neural networks")
-    /// 2. Multiple occurrences of query terms
-    /// 3. Rare terms in the query
+    ///   where `dl` is the result's word count and `avgdl` is the mean word
+    ///   count across the whole results batch, so scores stay comparable
+    ///   across documents of very different lengths. `tf` is a fuzzy term
+    ///   frequency: each tokenized word of the result text is run through the
+    ///   term's Levenshtein automaton, and a match at edit distance `d`
+    ///   contributes `1.0 / (1.0 + d)` instead of a full `1.0`, so exact
+    ///   matches still dominate near-misses.
+    /// - `Phrase` only scores on an exact, ordered, contiguous match of the
+    ///   whole span (e.g. "neural networks").
+    ///
+    /// `And` sums its children's scores, penalized if any child didn't match
+    /// at all, and `Or` takes the best-scoring child — used to let a term
+    /// and its synonyms compete without double-counting.
+    fn calculate_keyword_boost(
+        &self,
+        text: &str,
+        query_features: &QueryFeatures,
+        dl: f64,
+        avgdl: f64,
+    ) -> f64 {
+        let text_lower = text.to_lowercase();
+        let tokens: Vec<&str> = text_lower.split_whitespace().collect();
+
+        let tree_boost = self.evaluate_operation(
+            &query_features.query_tree,
+            &text_lower,
+            &tokens,
+            query_features,
+            dl,
+            avgdl,
+        );
+
+        // Auto-generated window phrases are a pure reward, summed outside
+        // the tree so a document missing one doesn't eat the `And` penalty
+        // that applies to genuinely required terms.
+        let bonus_boost: f64 = query_features
+            .bonus_phrases
+            .iter()
+            .map(|phrase| {
+                self.evaluate_operation(phrase, &text_lower, &tokens, query_features, dl, avgdl)
+            })
+            .sum();
+
+        // Cap the total boost to prevent over-domination
+        (tree_boost + bonus_boost).min(0.25) // Maximum 25% boost
+    }
+
+    /// Recursively scores a single `Operation` node against the result text.
+    fn evaluate_operation(
+        &self,
+        operation: &Operation,
+        text_lower: &str,
+        tokens: &[&str],
+        query_features: &QueryFeatures,
+        dl: f64,
+        avgdl: f64,
+    ) -> f64 {
+        match operation {
+            Operation::Term(term) => self.score_term(term, tokens, query_features, dl, avgdl),
+            Operation::Phrase(words) => self.score_phrase(words, text_lower, query_features),
+            Operation::And(children) => {
+                if children.is_empty() {
+                    return 0.0;
+                }
+
+                let mut sum = 0.0;
+                let mut missing = 0;
+                for child in children {
+                    let score = self
+                        .evaluate_operation(child, text_lower, tokens, query_features, dl, avgdl);
+                    if score <= 0.0 {
+                        missing += 1;
+                    }
+                    sum += score;
+                }
+
+                // Mild penalty proportional to how many required children
+                // failed to match at all, so a handful of misses in a long
+                // query don't wipe out the boost entirely.
+                let missing_fraction = missing as f64 / children.len() as f64;
+                sum * (1.0 - 0.3 * missing_fraction)
+            }
+            Operation::Or(children) => children
+                .iter()
+                .map(|child| {
+                    self.evaluate_operation(child, text_lower, tokens, query_features, dl, avgdl)
+                })
+                .fold(0.0, f64::max),
+        }
+    }
+
+    /// Scores a single term leaf via fuzzy-matched BM25, discounted by its
+    /// `term_weights` entry (e.g. synonym-derived terms).
+    fn score_term(
+        &self,
+        term: &str,
+        tokens: &[&str],
+        query_features: &QueryFeatures,
+        dl: f64,
+        avgdl: f64,
+    ) -> f64 {
+        let Some(matcher) = query_features.fuzzy_matchers.get(term) else {
+            return 0.0;
+        };
+
+        let tf: f64 = tokens
+            .iter()
+            .filter_map(|token| matcher.matches(token))
+            .map(|distance| 1.0 / (1.0 + distance as f64))
+            .sum();
+
+        if tf <= 0.0 {
+            return 0.0;
+        }
+
+        let idf = query_features.idf_map.get(term).copied().unwrap_or(1.0);
+        let k1 = self.weights.bm25_k1;
+        let b = self.weights.bm25_b;
+        let length_norm = 1.0 - b + b * (dl / avgdl.max(1.0));
+        let bm25_term = idf * (tf * (k1 + 1.0)) / (tf + k1 * length_norm);
+        let term_weight = query_features.term_weights.get(term).copied().unwrap_or(1.0);
+
+        bm25_term * term_weight
+    }
+
+    /// Scores a phrase leaf: a flat boost on an exact, ordered, contiguous
+    /// match of the whole span, discounted by its `term_weights` entry.
+    fn score_phrase(
+        &self,
+        words: &[String],
+        text_lower: &str,
+        query_features: &QueryFeatures,
+    ) -> f64 {
+        let phrase = words.join(" ");
+        if !text_lower.contains(&phrase) {
+            return 0.0;
+        }
+
+        let term_weight = query_features
+            .term_weights
+            .get(&phrase)
+            .copied()
+            .unwrap_or(1.0);
+
+        0.4 * term_weight
+    }
+
+    /// Calculates a boost for matched query terms appearing close together,
+    /// which the bag-of-words boosts above ignore entirely.
     ///
-    /// Example: Query "convolutional layers"
-    /// - Exact phrase match: +0.3 boost
-    /// - Individual word matches: +0.1 each
-    /// - Total possible boost: 0.5 (50%)
-    fn calculate_keyword_boost(&self, text: &str, query_features: &QueryFeatures) -> f64 {
+    /// Tokenizes the result text with positions, then fuzzy-matches each
+    /// distinct single-word query term (`Phrase` leaves are skipped — they
+    /// already require contiguity) against those positions. It then finds
+    /// the smallest window that contains at least one occurrence of every
+    /// matched term, via a sliding window over the merged, position-sorted
+    /// postings (the classic smallest-range-covering-k-lists pass). The
+    /// window width is converted into a boost that decays with distance, so
+    /// terms sitting right next to each other score near the maximum and
+    /// terms scattered across a long passage score near zero. Only applies
+    /// once at least two distinct terms match.
+    fn calculate_proximity_boost(&self, text: &str, query_features: &QueryFeatures) -> f64 {
+        let mut terms = Vec::new();
+        query_features.query_tree.collect_terms(&mut terms);
+        terms.sort();
+        terms.dedup();
+
         let text_lower = text.to_lowercase();
-        let mut total_boost = 0.0;
-
-        // Check each term from the query (both words and phrases)
-        for (term, query_frequency) in &query_features.word_frequencies {
-            // Case 1: Exact phrase match (e.g. "neural networks")
-            if term.contains(' ') && text_lower.contains(term) {
-                // Strong boost for exact phrase matches
-                total_boost += 0.4 * (*query_frequency as f64);
+        let tokens: Vec<&str> = text_lower.split_whitespace().collect();
+
+        // Collect (position, term_index) postings for every term with at
+        // least one fuzzy match, merged and sorted by position up front.
+        let mut postings: Vec<(usize, usize)> = Vec::new();
+        let mut matched_terms = 0;
+        for (term_index, term) in terms.iter().enumerate() {
+            let Some(matcher) = query_features.fuzzy_matchers.get(term) else {
                 continue;
+            };
+
+            let mut any_match = false;
+            for (position, token) in tokens.iter().enumerate() {
+                if matcher.matches(token).is_some() {
+                    postings.push((position, term_index));
+                    any_match = true;
+                }
+            }
+            if any_match {
+                matched_terms += 1;
             }
+        }
+
+        if matched_terms < 2 {
+            return 0.0;
+        }
+
+        postings.sort_by_key(|&(position, _)| position);
+
+        // Two-pointer sliding window: expand `right` until every distinct
+        // matched term is covered, then shrink `left` as far as possible
+        // while it still is, tracking the narrowest window seen.
+        let mut term_counts: HashMap<usize, usize> = HashMap::new();
+        let mut covered = 0;
+        let mut left = 0;
+        let mut best_width = usize::MAX;
 
-            // Case 2: Individual word matches
-            let text_frequency = text_lower.matches(term).count();
-            if text_frequency > 0 {
-                // Calculate boost based on:
-                // - Term frequency in query (sqrt-weighted)
-                // - Term frequency in text
-                let idf = query_features.idf_map.get(term).copied().unwrap_or(1.0);
-                let weight = ((*query_frequency as f64).sqrt()) * idf;
-                let boost = (text_frequency as f64) * weight * 0.1;
-                total_boost += boost;
+        for right in 0..postings.len() {
+            let (_, term_index) = postings[right];
+            let count = term_counts.entry(term_index).or_insert(0);
+            *count += 1;
+            if *count == 1 {
+                covered += 1;
+            }
+
+            while covered == matched_terms {
+                let width = postings[right].0 - postings[left].0 + 1;
+                best_width = best_width.min(width);
+
+                let (_, left_term) = postings[left];
+                let left_count = term_counts.get_mut(&left_term).unwrap();
+                *left_count -= 1;
+                if *left_count == 0 {
+                    covered -= 1;
+                }
+                left += 1;
             }
         }
 
-        // Cap the total boost to prevent over-domination
-        total_boost.min(0.25) // Maximum 25% boost
+        if best_width == usize::MAX {
+            return 0.0;
+        }
+
+        let matched_terms_ratio = matched_terms as f64 / terms.len().max(1) as f64;
+        let proximity_boost =
+            matched_terms_ratio / (1.0 + (best_width as f64 - matched_terms as f64));
+
+        proximity_boost.clamp(0.0, 0.25)
     }
 
     /// Calculates length optimization factor.
@@ -217,3 +451,99 @@ impl ScoreCalculator {
         bonus
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::text_analysis::TextAnalyzer;
+
+    fn features_for(query: &str) -> QueryFeatures {
+        TextAnalyzer::new().extract_query_features(query, None, None)
+    }
+
+    /// Single-word queries produce exactly one flat term, so the stemmed
+    /// form can be recovered from `term_weights` without hardcoding what the
+    /// stemmer does to a given word.
+    fn only_term(features: &QueryFeatures) -> String {
+        features.term_weights.keys().next().unwrap().clone()
+    }
+
+    #[test]
+    fn bm25_term_rewards_repeated_matches() {
+        let calculator = ScoreCalculator::new();
+        let features = features_for("machine");
+        let term = only_term(&features);
+
+        let one_match = calculator.score_term(&term, &["machine"], &features, 5.0, 5.0);
+        let three_matches = calculator.score_term(
+            &term,
+            &["machine", "machine", "machine"],
+            &features,
+            5.0,
+            5.0,
+        );
+
+        assert!(one_match > 0.0);
+        assert!(three_matches > one_match, "repeated terms should score higher");
+    }
+
+    #[test]
+    fn bm25_term_penalizes_documents_longer_than_average() {
+        let calculator = ScoreCalculator::new();
+        let features = features_for("machine");
+        let term = only_term(&features);
+        let tokens = ["machine"];
+
+        let at_average = calculator.score_term(&term, &tokens, &features, 5.0, 5.0);
+        let above_average = calculator.score_term(&term, &tokens, &features, 20.0, 5.0);
+
+        assert!(
+            above_average < at_average,
+            "a longer-than-average document should be penalized by length normalization"
+        );
+    }
+
+    #[test]
+    fn bm25_term_with_no_fuzzy_match_is_zero() {
+        let calculator = ScoreCalculator::new();
+        let features = features_for("machine");
+        let term = only_term(&features);
+
+        assert_eq!(
+            calculator.score_term(&term, &["weather", "today"], &features, 5.0, 5.0),
+            0.0
+        );
+    }
+
+    #[test]
+    fn proximity_boost_rewards_adjacent_terms_over_scattered_ones() {
+        let calculator = ScoreCalculator::new();
+        let features = features_for("neural networks");
+
+        let adjacent = calculator.calculate_proximity_boost(
+            "the neural networks paper was published",
+            &features,
+        );
+        let scattered = calculator.calculate_proximity_boost(
+            "neural information is processed while far away networks compute results",
+            &features,
+        );
+
+        assert!(adjacent > 0.0);
+        assert!(
+            adjacent > scattered,
+            "terms appearing next to each other should score higher than scattered terms"
+        );
+    }
+
+    #[test]
+    fn proximity_boost_is_zero_with_fewer_than_two_matched_terms() {
+        let calculator = ScoreCalculator::new();
+        let features = features_for("neural networks");
+
+        assert_eq!(
+            calculator.calculate_proximity_boost("neural is the only match here", &features),
+            0.0
+        );
+    }
+}
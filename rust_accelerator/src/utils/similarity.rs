@@ -53,3 +53,23 @@ impl SimilarityCalculator {
         jaccard(text1, text2)
     }
 }
+
+/// Calculates cosine similarity between two embedding vectors.
+///
+/// Returns 0.0 for mismatched/empty vectors or a zero-magnitude vector,
+/// rather than producing `NaN`.
+pub fn cosine_similarity(a: &[f64], b: &[f64]) -> f64 {
+    if a.len() != b.len() || a.is_empty() {
+        return 0.0;
+    }
+
+    let dot: f64 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a = a.iter().map(|x| x * x).sum::<f64>().sqrt();
+    let norm_b = b.iter().map(|x| x * x).sum::<f64>().sqrt();
+
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 0.0;
+    }
+
+    dot / (norm_a * norm_b)
+}
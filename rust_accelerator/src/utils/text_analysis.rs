@@ -1,10 +1,17 @@
 //! Text preprocessing and analysis utilities.
 
 use crate::models::internal::QueryFeatures;
+use crate::utils::fuzzy_matching::build_matchers;
+use crate::utils::query_tree::Operation;
 use rust_stemmers::{Algorithm, Stemmer};
 use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 use stop_words::{LANGUAGE, get};
 
+/// Discount applied to the scoring weight of synonym-derived terms, so
+/// genuine query words still rank above synonym matches.
+const SYNONYM_WEIGHT_DISCOUNT: f64 = 0.5;
+
 /// Handles text analysis and preprocessing operations.
 pub struct TextAnalyzer {
     /// Set of common words to ignore during analysis
@@ -27,40 +34,189 @@ impl TextAnalyzer {
         &self,
         query: &str,
         idf_map: Option<HashMap<String, f64>>,
+        synonyms: Option<HashMap<String, Vec<String>>>,
     ) -> QueryFeatures {
-        // Step 1: Normalize to lowercase for consistent matching
+        // Step 1: Normalize to lowercase, then pull double-quoted spans out
+        // as explicit phrases before splitting the remainder into words.
         let normalized = query.to_lowercase();
-
-        // Step 2: Extract meaningful single words with basic stemming
+        let (remaining, quoted_phrases) = extract_quoted_phrases(&normalized);
+        let query_words: Vec<&str> = remaining.split_whitespace().collect();
         let stemmer = Stemmer::create(Algorithm::English);
-        let single_words: Vec<String> = normalized
-            .split_whitespace()
-            .filter(|word| !self.stop_words.contains(*word) && word.len() >= 2)
-            .map(|word| stemmer.stem(word).to_string())
-            .collect();
 
-        // Step 3: Extract meaningful 2-word phrases
-        let words: Vec<&str> = normalized.split_whitespace().collect();
-        let mut phrases = Vec::new();
-
-        for window in words.windows(2) {
+        // Step 2: Multi-word phrases, both explicit (quoted) and implicit
+        // (2-word windows). Each becomes a flat term (for term_weights/fuzzy
+        // bookkeeping, as before). Explicit phrases are something the caller
+        // asked for, so they become a mandatory `Phrase` node in the tree;
+        // implicit windows are a bonus reward scored separately (see
+        // `bonus_phrases`) so a document isn't penalized for missing a
+        // contiguous span nobody actually requested.
+        let mut window_phrases: Vec<Vec<String>> = Vec::new();
+        for window in query_words.windows(2) {
             if !self.stop_words.contains(window[0]) && !self.stop_words.contains(window[1]) {
-                phrases.push(window.join(" "));
+                window_phrases.push(vec![window[0].to_string(), window[1].to_string()]);
             }
         }
 
-        // Step 4: Combine single words and phrases into one vector
-        let all_terms: Vec<String> = single_words.into_iter().chain(phrases).collect();
+        let mut term_weights: HashMap<String, f64> = HashMap::new();
+        let mut all_terms: Vec<String> = Vec::new();
+        let mut and_children: Vec<Operation> = Vec::new();
+        let mut bonus_phrases: Vec<Operation> = Vec::new();
+
+        for words in &quoted_phrases {
+            let key = words.join(" ");
+            term_weights.entry(key.clone()).or_insert(1.0);
+            all_terms.push(key);
+            and_children.push(Operation::Phrase(words.clone()));
+        }
+
+        for words in &window_phrases {
+            let key = words.join(" ");
+            term_weights.entry(key.clone()).or_insert(1.0);
+            all_terms.push(key);
+            bonus_phrases.push(Operation::Phrase(words.clone()));
+        }
+
+        // Step 3: Walk each meaningful single word, stemming it and pairing
+        // it with any caller-supplied synonyms. This builds the flat
+        // term_weights/all_terms bookkeeping and an `Or` tree node — grouping
+        // a term with its synonyms — in the same pass. Multi-word synonyms
+        // are inserted as phrase terms so they flow through the exact-phrase
+        // branch in scoring instead of inflating the single-word term set,
+        // and every synonym-derived term is discounted so genuine query
+        // words still rank above synonym matches.
+        for word in &query_words {
+            if self.stop_words.contains(*word) || word.len() < 2 {
+                continue;
+            }
+
+            let stemmed = stemmer.stem(word).to_string();
+            term_weights.entry(stemmed.clone()).or_insert(1.0);
+            all_terms.push(stemmed.clone());
+            let mut or_children = vec![Operation::Term(stemmed)];
 
-        // Step 5: Count term frequencies
+            if let Some(expansions) = synonyms.as_ref().and_then(|s| s.get(*word)) {
+                for expansion in expansions {
+                    let expansion = expansion.to_lowercase();
+
+                    let node = if expansion.contains(' ') {
+                        let words: Vec<String> =
+                            expansion.split_whitespace().map(str::to_string).collect();
+                        let key = words.join(" ");
+                        term_weights
+                            .entry(key.clone())
+                            .or_insert(SYNONYM_WEIGHT_DISCOUNT);
+                        all_terms.push(key);
+                        Operation::Phrase(words)
+                    } else if self.stop_words.contains(expansion.as_str()) || expansion.len() < 2
+                    {
+                        continue;
+                    } else {
+                        let stemmed_expansion = stemmer.stem(&expansion).to_string();
+                        term_weights
+                            .entry(stemmed_expansion.clone())
+                            .or_insert(SYNONYM_WEIGHT_DISCOUNT);
+                        all_terms.push(stemmed_expansion.clone());
+                        Operation::Term(stemmed_expansion)
+                    };
+
+                    or_children.push(node);
+                }
+            }
+
+            and_children.push(if or_children.len() == 1 {
+                or_children.into_iter().next().unwrap()
+            } else {
+                Operation::Or(or_children)
+            });
+        }
+
+        // Step 4: Count term frequencies
         let mut word_frequencies = HashMap::new();
         for term in &all_terms {
             *word_frequencies.entry(term.clone()).or_insert(0) += 1;
         }
 
+        // Step 5: Build one Levenshtein automaton per single-word term so
+        // scoring can fuzzy-match typos without rebuilding it per result.
+        let fuzzy_matchers = build_matchers(all_terms)
+            .into_iter()
+            .map(|(term, matcher)| (term, Arc::new(matcher)))
+            .collect();
+
         QueryFeatures {
             word_frequencies,
             idf_map: idf_map.unwrap_or_default(),
+            fuzzy_matchers,
+            term_weights,
+            query_tree: Operation::And(and_children),
+            bonus_phrases,
         }
     }
 }
+
+/// Splits double-quoted spans out of an already-lowercased query, returning
+/// the remaining unquoted text and the word lists of each quoted phrase.
+fn extract_quoted_phrases(normalized: &str) -> (String, Vec<Vec<String>>) {
+    let mut remaining = String::new();
+    let mut phrases = Vec::new();
+
+    for (index, segment) in normalized.split('"').enumerate() {
+        if index % 2 == 1 {
+            let words: Vec<String> = segment.split_whitespace().map(str::to_string).collect();
+            if !words.is_empty() {
+                phrases.push(words);
+            }
+        } else {
+            remaining.push_str(segment);
+            remaining.push(' ');
+        }
+    }
+
+    (remaining, phrases)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extract_quoted_phrases_pulls_out_a_single_phrase() {
+        let (remaining, phrases) = extract_quoted_phrases(r#"find "neural networks" papers"#);
+
+        assert_eq!(phrases, vec![vec!["neural".to_string(), "networks".to_string()]]);
+        assert_eq!(remaining.split_whitespace().collect::<Vec<_>>(), ["find", "papers"]);
+    }
+
+    #[test]
+    fn extract_quoted_phrases_handles_multiple_phrases() {
+        let (remaining, phrases) =
+            extract_quoted_phrases(r#""deep learning" vs "neural networks""#);
+
+        assert_eq!(
+            phrases,
+            vec![
+                vec!["deep".to_string(), "learning".to_string()],
+                vec!["neural".to_string(), "networks".to_string()],
+            ]
+        );
+        assert_eq!(remaining.split_whitespace().collect::<Vec<_>>(), ["vs"]);
+    }
+
+    #[test]
+    fn extract_quoted_phrases_with_no_quotes_returns_no_phrases() {
+        let (remaining, phrases) = extract_quoted_phrases("machine learning basics");
+
+        assert!(phrases.is_empty());
+        assert_eq!(
+            remaining.split_whitespace().collect::<Vec<_>>(),
+            ["machine", "learning", "basics"]
+        );
+    }
+
+    #[test]
+    fn extract_quoted_phrases_ignores_an_empty_quoted_span() {
+        let (_, phrases) = extract_quoted_phrases(r#"find "" here"#);
+
+        assert!(phrases.is_empty());
+    }
+}